@@ -42,6 +42,12 @@ pub enum SupplyMeasure {
     /// Maximum issue limit, defined as a sum of all genesis-defined inflation
     /// allowed amounts, plus amount of assets issued in genesis
     IssueLimit = 2,
+
+    /// Supply backed only by operations whose witness transaction has
+    /// reached a caller-defined minimum number of confirmations, as opposed
+    /// to [`SupplyMeasure::KnownCirculating`] which also counts
+    /// mempool-only operations
+    ConfirmedCirculating = 3,
 }
 
 /// Structure providing extended information about the asset supply, derived
@@ -563,6 +569,1007 @@ impl BurnReplace {
     }
 }
 
-// TODO #34: Define consistency trait with operations like `is_consistent` and
-//       `make_consistent`, checking internal consistency of the denormalized
-//       data within each type of the RGB20 structures
+/// Errors detected while verifying internal consistency of the denormalized
+/// RGB20 supply data structures.
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum InconsistencyError {
+    /// known circulating supply {0} exceeds the issue limit {1}
+    SupplyExceedsLimit(AtomicValue, AtomicValue),
+
+    /// burn & replace operation {0} reports `supply_change` {1}, which does
+    /// not equal `burned_amount` {2} minus `replaced_amount` {3}
+    SupplyChangeMismatch(NodeId, AtomicValue, AtomicValue, AtomicValue),
+
+    /// burn & replace operation {0} is marked as a pure burn
+    /// (`does_replacement` is `false`) but has a non-zero `replaced_amount`
+    /// of {1}
+    PureBurnWithReplacement(NodeId, AtomicValue),
+
+    /// burn & replace operation {0} replaces more than it burns:
+    /// `replaced_amount` {1} exceeds `burned_amount` {2}
+    ReplacementExceedsBurn(NodeId, AtomicValue, AtomicValue),
+
+    /// epoch {0} has `is_final` set to {1}, which does not match the
+    /// presence of its `epoch_seal`
+    EpochFinalityMismatch(NodeId, bool),
+
+    /// epoch {0} has `is_unlocked` set to {1}, which does not match the
+    /// presence of its `seal`
+    EpochUnlockedMismatch(NodeId, bool),
+
+    /// epoch {0} known operations are not sequentially numbered starting
+    /// from 1
+    EpochOperationNumbering(NodeId),
+
+    /// epoch {0} operation {1} closes seal {2}, which does not match the
+    /// seal {3} left open by the preceding operation (or the epoch itself)
+    EpochSealChainBroken(NodeId, NodeId, OutPoint, OutPoint),
+
+    /// epoch {0} has `seal` set to `None` (nothing unlocks a burn or
+    /// replace operation) but its `known_operations` is non-empty
+    EpochOperationsWithoutSeal(NodeId),
+
+    /// issue {0} has an empty `closes` set (i.e. is a primary/genesis
+    /// issue) but carries a witness {1:?}, or has a non-empty `closes` set
+    /// but carries no witness; primary issues never have a witness of their
+    /// own and secondary issues always do
+    IssueWitnessMismatch(NodeId, Option<Txid>),
+}
+
+/// Trait for verifying and repairing internal consistency of the
+/// denormalized RGB20 contract data structures.
+///
+/// The structures in this module cache values (flags, sums, sequence
+/// numbers) which are in principle derivable from other fields of the same
+/// structure. This trait lets library users check that a value obtained
+/// from an untrusted source (or reconstructed by hand) has not been
+/// tampered with or corrupted, and to repair it when that's the case.
+pub trait SupplyConsistency {
+    /// Checks that all denormalized fields are consistent with each other,
+    /// returning the first inconsistency found.
+    fn is_consistent(&self) -> Result<(), InconsistencyError>;
+
+    /// Recomputes all denormalized fields from the authoritative data. Never
+    /// fails: on inconsistent input it simply replaces the offending fields
+    /// with the values derived from the rest of the structure.
+    ///
+    /// Inconsistencies in data that is not derivable (a missing witness of a
+    /// secondary issue, epoch operation numbering or seal-chain breaks,
+    /// operations in a locked epoch) are left as they are, so
+    /// [`is_consistent`](SupplyConsistency::is_consistent) may still report
+    /// them afterwards.
+    fn make_consistent(&mut self);
+}
+
+impl SupplyConsistency for Supply {
+    fn is_consistent(&self) -> Result<(), InconsistencyError> {
+        if self.known_circulating > self.issue_limit {
+            return Err(InconsistencyError::SupplyExceedsLimit(
+                self.known_circulating,
+                self.issue_limit,
+            ));
+        }
+        Ok(())
+    }
+
+    fn make_consistent(&mut self) {
+        if self.known_circulating > self.issue_limit {
+            self.known_circulating = self.issue_limit;
+        }
+    }
+}
+
+impl SupplyConsistency for Issue {
+    fn is_consistent(&self) -> Result<(), InconsistencyError> {
+        if self.closes.is_empty() != self.witness.is_none() {
+            return Err(InconsistencyError::IssueWitnessMismatch(
+                self.node_id,
+                self.witness,
+            ));
+        }
+        Ok(())
+    }
+
+    fn make_consistent(&mut self) {
+        // A primary issue never has a witness of its own; a secondary one
+        // always does, but if it's missing here there is no value we can
+        // derive it from, so we can only repair the primary case.
+        if self.closes.is_empty() {
+            self.witness = None;
+        }
+    }
+}
+
+impl SupplyConsistency for BurnReplace {
+    fn is_consistent(&self) -> Result<(), InconsistencyError> {
+        if self.replaced_amount > self.burned_amount {
+            return Err(InconsistencyError::ReplacementExceedsBurn(
+                self.node_id,
+                self.replaced_amount,
+                self.burned_amount,
+            ));
+        }
+        if !self.does_replacement && self.replaced_amount != 0 {
+            return Err(InconsistencyError::PureBurnWithReplacement(
+                self.node_id,
+                self.replaced_amount,
+            ));
+        }
+        if self.supply_change != self.burned_amount - self.replaced_amount {
+            return Err(InconsistencyError::SupplyChangeMismatch(
+                self.node_id,
+                self.supply_change,
+                self.burned_amount,
+                self.replaced_amount,
+            ));
+        }
+        Ok(())
+    }
+
+    fn make_consistent(&mut self) {
+        if self.replaced_amount > self.burned_amount {
+            self.replaced_amount = self.burned_amount;
+        }
+        if !self.does_replacement {
+            self.replaced_amount = 0;
+        }
+        self.supply_change = self.burned_amount - self.replaced_amount;
+    }
+}
+
+impl SupplyConsistency for Epoch {
+    fn is_consistent(&self) -> Result<(), InconsistencyError> {
+        if self.is_final != self.epoch_seal.is_none() {
+            return Err(InconsistencyError::EpochFinalityMismatch(
+                self.node_id,
+                self.is_final,
+            ));
+        }
+        if self.is_unlocked != self.seal.is_some() {
+            return Err(InconsistencyError::EpochUnlockedMismatch(
+                self.node_id,
+                self.is_unlocked,
+            ));
+        }
+        if self.seal.is_none() && !self.known_operations.is_empty() {
+            return Err(InconsistencyError::EpochOperationsWithoutSeal(self.node_id));
+        }
+
+        let mut expected_no = 1usize;
+        let mut open_seal = self.seal;
+        for operation in &self.known_operations {
+            if operation.no != expected_no {
+                return Err(InconsistencyError::EpochOperationNumbering(self.node_id));
+            }
+            if let Some(seal) = open_seal {
+                if operation.closes != seal {
+                    return Err(InconsistencyError::EpochSealChainBroken(
+                        self.node_id,
+                        operation.node_id,
+                        operation.closes,
+                        seal,
+                    ));
+                }
+            }
+            open_seal = operation.seal;
+            expected_no += 1;
+        }
+
+        Ok(())
+    }
+
+    fn make_consistent(&mut self) {
+        self.is_final = self.epoch_seal.is_none();
+        self.is_unlocked = self.seal.is_some();
+    }
+}
+
+/// Confirmation status of a witness transaction, as reported by a
+/// [`WitnessResolver`].
+#[derive(
+    Getters, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display,
+)]
+#[display(Debug)]
+pub struct WitnessStatus {
+    /// Height of the block containing the witness transaction, or `None` if
+    /// the transaction is known but not yet mined (mempool-only)
+    height: Option<u32>,
+
+    /// Number of confirmations accumulated by the witness transaction; `0`
+    /// if it is mempool-only
+    depth: u32,
+}
+
+impl WitnessStatus {
+    /// Constructor for structure initialization.
+    #[inline]
+    pub fn with(height: Option<u32>, depth: u32) -> WitnessStatus {
+        WitnessStatus { height, depth }
+    }
+}
+
+/// Resolves mining status for a witness transaction.
+///
+/// This mirrors the `witness_info` capability RGB contract state readers
+/// use to let wallets tell settled state from pending state, applied here
+/// to the supply-changing operations tracked by this module.
+pub trait WitnessResolver {
+    /// Returns confirmation information for `txid`, or `None` if the
+    /// resolver has no knowledge of the transaction at all (neither mined
+    /// nor in the mempool).
+    fn witness_info(&self, txid: Txid) -> Option<WitnessStatus>;
+}
+
+/// Common interface for RGB20 supply-changing operations which can be
+/// checked for witness confirmation depth.
+pub trait Confirmable {
+    /// Witness transaction id backing this operation, or `None` if the
+    /// operation (e.g. a primary/genesis issue) has no witness of its own.
+    fn witness_txid(&self) -> Option<Txid>;
+
+    /// Detects whether the operation's witness has reached at least
+    /// `min_depth` confirmations. Operations without a witness are always
+    /// considered confirmed, since they are bound by the contract genesis
+    /// rather than by a mined transaction.
+    fn is_confirmed(&self, resolver: &impl WitnessResolver, min_depth: u32) -> bool {
+        match self.witness_txid() {
+            None => true,
+            Some(txid) => resolver
+                .witness_info(txid)
+                .map(|status| status.depth >= min_depth)
+                .unwrap_or(false),
+        }
+    }
+}
+
+impl Confirmable for Issue {
+    #[inline]
+    fn witness_txid(&self) -> Option<Txid> {
+        self.witness
+    }
+}
+
+impl Confirmable for BurnReplace {
+    #[inline]
+    fn witness_txid(&self) -> Option<Txid> {
+        Some(self.witness)
+    }
+}
+
+impl Confirmable for Epoch {
+    #[inline]
+    fn witness_txid(&self) -> Option<Txid> {
+        Some(self.witness)
+    }
+}
+
+/// Partitions a set of supply-changing operations into those confirmed to
+/// at least `min_depth` and those still only known from the mempool (or
+/// entirely unresolved).
+pub fn partition_by_confirmation<'o, O: Confirmable>(
+    operations: &'o [O],
+    resolver: &impl WitnessResolver,
+    min_depth: u32,
+) -> (Vec<&'o O>, Vec<&'o O>) {
+    operations
+        .iter()
+        .partition(|op| op.is_confirmed(resolver, min_depth))
+}
+
+impl Supply {
+    /// Computes the [`SupplyMeasure::ConfirmedCirculating`] figure: the sum
+    /// of all primary and confirmed secondary issues, less the net supply
+    /// change of all confirmed burn & replace operations.
+    ///
+    /// Unlike [`total_circulating`](Supply::total_circulating), this does
+    /// not require every supply-changing operation to be known; it only
+    /// requires the caller to supply the operations it does know about,
+    /// together with a resolver able to tell which of them have settled.
+    pub fn confirmed_circulating(
+        &self,
+        issues: &[Issue],
+        burn_replaces: &[BurnReplace],
+        resolver: &impl WitnessResolver,
+        min_depth: u32,
+    ) -> AtomicValue {
+        let issued: AtomicValue = issues
+            .iter()
+            .filter(|issue| issue.is_confirmed(resolver, min_depth))
+            .map(|issue| issue.amount)
+            .sum();
+        let net_burned: AtomicValue = burn_replaces
+            .iter()
+            .filter(|burn| burn.is_confirmed(resolver, min_depth))
+            .map(|burn| burn.supply_change)
+            .sum();
+        issued.saturating_sub(net_burned)
+    }
+}
+
+/// Errors detected while assembling an [`EpochChain`] out of a set of known
+/// [`Epoch`]s.
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum EpochChainError {
+    /// no epochs were provided to assemble a chain from
+    Empty,
+
+    /// epoch {0} is not the only known epoch closing seal {1}; this
+    /// indicates a branch (double-spend) of the epoch-opening right
+    Branch(NodeId, OutPoint),
+
+    /// epoch chain is missing one or more epochs: seal {0}, opened by a
+    /// known epoch, is not closed by any other known epoch
+    Gap(OutPoint),
+
+    /// epoch {0}, closing seal {1}, was never reached while walking the
+    /// chain from its start; the chain already terminated in a final epoch
+    /// before getting to it, which means it either belongs to a different
+    /// chain or was produced after the chain was supposed to be closed
+    Unreachable(NodeId, OutPoint),
+}
+
+/// Contract-wide view across an asset's burn & replace epochs, assembled by
+/// following `epoch_seal` from the epoch closing the genesis epoch seal
+/// through every subsequent [`Epoch`].
+///
+/// This turns the per-epoch `known_operations` into a single ledger of
+/// everything burned or replaced across the contract's whole lifetime, and
+/// tells whether that ledger is provably complete.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EpochChain {
+    /// Epochs ordered as encountered while walking the chain from its start
+    epochs: Vec<Epoch>,
+
+    /// Whether the chain has no gaps and terminates in an epoch with
+    /// `is_final` set to `true`
+    is_complete: bool,
+}
+
+impl EpochChain {
+    /// Assembles an epoch chain out of an ordered collection of known
+    /// epochs (as returned from the stash, first being the epoch closing
+    /// the genesis epoch seal), linking each epoch's `epoch_seal` to the
+    /// `closes` of the epoch that spent it.
+    pub fn with(
+        epochs: impl IntoIterator<Item = Epoch>,
+    ) -> Result<EpochChain, EpochChainError> {
+        let epochs: Vec<Epoch> = epochs.into_iter().collect();
+        let first_closes = epochs.first().ok_or(EpochChainError::Empty)?.closes;
+
+        let mut by_closes: BTreeMap<OutPoint, Vec<Epoch>> = BTreeMap::new();
+        for epoch in epochs {
+            by_closes.entry(epoch.closes).or_default().push(epoch);
+        }
+        for group in by_closes.values() {
+            if group.len() > 1 {
+                let epoch = &group[1];
+                return Err(EpochChainError::Branch(epoch.node_id, epoch.closes));
+            }
+        }
+
+        let mut chain = Vec::with_capacity(by_closes.len());
+        let mut cursor = first_closes;
+        let mut terminated = false;
+        while let Some(mut group) = by_closes.remove(&cursor) {
+            let epoch = group.remove(0);
+            match epoch.epoch_seal {
+                Some(seal) => {
+                    chain.push(epoch);
+                    cursor = seal;
+                }
+                None => {
+                    chain.push(epoch);
+                    terminated = true;
+                    break;
+                }
+            }
+        }
+
+        if !terminated {
+            // the walk stopped because no known epoch closes `cursor`: that
+            // is the seal which is missing an epoch, not the `closes` of
+            // whatever epoch happens to be left over in the map
+            if !by_closes.is_empty() {
+                return Err(EpochChainError::Gap(cursor));
+            }
+        } else if let Some(group) = by_closes.into_values().next() {
+            // the walk already terminated in a final epoch, so whatever is
+            // left over did not hang off the chain we just walked; report
+            // it as unreachable rather than mislabeling it a `Gap`, which
+            // is reserved for seals opened by the chain itself
+            let epoch = &group[0];
+            return Err(EpochChainError::Unreachable(epoch.node_id, epoch.closes));
+        }
+
+        let is_complete = chain.last().map(|epoch| epoch.is_final).unwrap_or(false);
+
+        Ok(EpochChain { epochs: chain, is_complete })
+    }
+
+    /// Detects whether the chain has no gaps and terminates in an epoch
+    /// which does not allow opening any further epoch (i.e. all epoch seals
+    /// for burn & replace operations are provably accounted for).
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.is_complete
+    }
+
+    /// Sum of [`BurnReplace::burned_amount`] across every operation of
+    /// every epoch in the chain.
+    pub fn total_burned(&self) -> AtomicValue {
+        self.operations().map(|op| op.burned_amount).sum()
+    }
+
+    /// Sum of [`BurnReplace::replaced_amount`] across every operation of
+    /// every epoch in the chain.
+    pub fn total_replaced(&self) -> AtomicValue {
+        self.operations().map(|op| op.replaced_amount).sum()
+    }
+
+    /// Net change to the circulating supply across the whole chain, i.e.
+    /// [`total_burned`](EpochChain::total_burned) minus
+    /// [`total_replaced`](EpochChain::total_replaced).
+    pub fn net_supply_change(&self) -> AtomicValue {
+        self.operations().map(|op| op.supply_change).sum()
+    }
+
+    fn operations(&self) -> impl Iterator<Item = &BurnReplace> {
+        self.epochs.iter().flat_map(|epoch| &epoch.known_operations)
+    }
+}
+
+/// Errors detected while reconciling issued amounts against the inflation
+/// rights they were supposed to have been produced from.
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum InflationLedgerError {
+    /// secondary issue {0} closes seal {1}, which is not a known unspent
+    /// inflation seal of the genesis or any prior issue
+    UnknownSeal(NodeId, OutPoint),
+
+    /// secondary issue {0} issues {1}, which exceeds the {2} of inflation
+    /// capacity available across the seals it closed
+    OverIssuance(NodeId, AtomicValue, AtomicValue),
+}
+
+/// Reconciles the `inflation_assignments` declared by a genesis and its
+/// secondary issues against the amounts those secondary issues actually
+/// claim, turning the raw assignment map into enforceable supply-cap
+/// accounting.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InflationLedger {
+    /// Inflation capacity still available under each seal that has not yet
+    /// been spent by a secondary issue
+    remaining: BTreeMap<OutPoint, AtomicValue>,
+}
+
+impl InflationLedger {
+    /// Builds an inflation ledger from the genesis issue and its secondary
+    /// issues.
+    ///
+    /// `secondary` may be given in any order: issues are resolved in
+    /// dependency order rather than iteration order, so a second-generation
+    /// issue may appear before the first-generation issue that introduces
+    /// the inflation seal it spends. An issue is only reported as
+    /// [`InflationLedgerError::UnknownSeal`] once none of the remaining
+    /// issues can make any further progress.
+    pub fn with(
+        genesis: &Issue,
+        secondary: impl IntoIterator<Item = Issue>,
+    ) -> Result<InflationLedger, InflationLedgerError> {
+        let mut remaining: BTreeMap<OutPoint, AtomicValue> = genesis
+            .inflation_assignments
+            .iter()
+            .map(|(seal, (cap, _))| (*seal, *cap))
+            .collect();
+
+        let mut pending: Vec<Issue> = secondary.into_iter().collect();
+        while !pending.is_empty() {
+            let mut still_pending = Vec::with_capacity(pending.len());
+            let mut progressed = false;
+
+            for issue in pending {
+                if !issue.closes.iter().all(|seal| remaining.contains_key(seal)) {
+                    still_pending.push(issue);
+                    continue;
+                }
+
+                let mut available: AtomicValue = 0;
+                for seal in &issue.closes {
+                    available += remaining.remove(seal).expect("just checked present");
+                }
+                if issue.amount > available {
+                    return Err(InflationLedgerError::OverIssuance(
+                        issue.node_id,
+                        issue.amount,
+                        available,
+                    ));
+                }
+                for (seal, (cap, _)) in &issue.inflation_assignments {
+                    remaining.insert(*seal, *cap);
+                }
+                progressed = true;
+            }
+
+            if !progressed {
+                let issue = &still_pending[0];
+                let seal = issue
+                    .closes
+                    .iter()
+                    .find(|seal| !remaining.contains_key(seal))
+                    .copied()
+                    .expect("issue stayed pending because some seal was unresolved");
+                return Err(InflationLedgerError::UnknownSeal(issue.node_id, seal));
+            }
+
+            pending = still_pending;
+        }
+
+        Ok(InflationLedger { remaining })
+    }
+
+    /// Total inflation capacity remaining across all still-unspent seals.
+    pub fn remaining_inflation(&self) -> AtomicValue {
+        self.remaining.values().sum()
+    }
+
+    /// Detects whether every inflation seal has been spent (or never
+    /// existed), leaving no further secondary issuance possible.
+    pub fn is_inflation_exhausted(&self) -> bool {
+        self.remaining_inflation() == 0
+    }
+}
+
+/// Data structure keeping information about an asset renomination operation,
+/// i.e. a change of the asset's ticker, name and/or precision.
+///
+/// Structure fields are immutable since they are bound with
+/// client-side-validation commitments and can't be changed.
+#[derive(
+    Getters,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Display,
+    StrictEncode,
+    StrictDecode,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{node_id} -> {ticker}")]
+pub struct Renomination {
+    /// Unique primary key; equals to the state transition id that performs
+    /// this renomination
+    node_id: NodeId,
+
+    /// Contract ID to which this renomination is related to
+    contract_id: ContractId,
+
+    /// Indicates transaction output/seal which had an assigned renomination
+    /// right and which spending performed this renomination
+    closes: OutPoint,
+
+    /// New ticker of the asset, established by this renomination
+    ticker: String,
+
+    /// New name of the asset, established by this renomination
+    name: String,
+
+    /// New decimal precision of the asset, established by this renomination
+    precision: u8,
+
+    /// Seal controlling the next renomination right.
+    ///
+    /// This can be set to `None` in case if the renomination does not allow
+    /// the ticker, name or precision to be changed again in the future.
+    seal: Option<OutPoint>,
+
+    /// Witness transaction id, which should be present in the commitment
+    /// medium (bitcoin blockchain or state channel) to make the operation
+    /// valid
+    witness: Txid,
+}
+
+/// Errors detected while constructing a [`Renomination`] from a state
+/// transition.
+///
+/// This module does not own `crate::asset::Error` (renomination tracking
+/// has no variant reserved for it there yet), so [`Renomination::with`]
+/// reports its own dedicated error type rather than silently reusing one
+/// that means something else.
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum RenominationError {
+    /// renomination transition {0} is missing its ticker, name or precision
+    /// metadata
+    UnsatisfiedSchemaRequirement(NodeId),
+
+    /// renomination transition {0} reveals its renomination seal only in
+    /// confidential form
+    SealConfidential(NodeId),
+}
+
+impl Renomination {
+    /// Validates and assembles a [`Renomination`] out of already-extracted
+    /// field values. Kept separate from [`Renomination::with`] so the
+    /// [`RenominationError`] paths can be exercised without needing a real
+    /// [`Transition`].
+    fn new(
+        node_id: NodeId,
+        contract_id: ContractId,
+        closes: OutPoint,
+        ticker: Option<String>,
+        name: Option<String>,
+        precision: Option<u8>,
+        seal: Option<OutPoint>,
+        witness: Txid,
+    ) -> Result<Self, RenominationError> {
+        let ticker =
+            ticker.ok_or(RenominationError::UnsatisfiedSchemaRequirement(node_id))?;
+        let name = name.ok_or(RenominationError::UnsatisfiedSchemaRequirement(node_id))?;
+        let precision =
+            precision.ok_or(RenominationError::UnsatisfiedSchemaRequirement(node_id))?;
+
+        Ok(Renomination {
+            node_id,
+            contract_id,
+            closes,
+            ticker,
+            name,
+            precision,
+            seal,
+            witness,
+        })
+    }
+
+    /// Constructor for [`Renomination`] structure initialization. Can not be
+    /// used externally; the structure is always created from RGB contract
+    /// data.
+    ///
+    /// Reading the renomination right via `OwnedRightType::Renomination`
+    /// relies on the schema reserving that variant, exactly as
+    /// [`Issue::with`] already relies on `OwnedRightType::Inflation`,
+    /// [`Epoch::with`] on `OwnedRightType::OpenEpoch` and
+    /// [`BurnReplace::with`] on `OwnedRightType::BurnReplace`:
+    /// none of those schema-side variants live in this module, and adding
+    /// them is tracked wherever the rest of the schema is defined, not here.
+    pub(crate) fn with(
+        contract_id: ContractId,
+        closes: OutPoint,
+        transition: &Transition,
+        witness: Txid,
+    ) -> Result<Self, RenominationError> {
+        let id = transition.node_id();
+
+        let ticker = transition.metadata().string(FieldType::Ticker).first().cloned();
+        let name = transition.metadata().string(FieldType::Name).first().cloned();
+        let precision = transition.metadata().u8(FieldType::Precision).first().copied();
+
+        let seal = transition
+            .revealed_seals_by_type(OwnedRightType::Renomination.into())
+            .map_err(|_| RenominationError::SealConfidential(id))?
+            .first()
+            .copied()
+            .map(|seal| seal.to_outpoint_reveal(witness))
+            .map(OutPoint::from);
+
+        Renomination::new(id, contract_id, closes, ticker, name, precision, seal, witness)
+    }
+
+    /// Builds every known renomination out of the renomination-type
+    /// transitions found in the stash, in the order the transitions are
+    /// given; they are not re-sorted by the order their closed seals were
+    /// spent.
+    ///
+    /// This has no caller within this module: the stash-walking code that would invoke it (alongside the
+    /// equivalent assembly already done there for [`Issue`], [`Epoch`] and
+    /// [`BurnReplace`]) lives outside `supply.rs`, which only models the
+    /// supply-side data once it has been extracted from the stash.
+    pub fn from_transitions<'t>(
+        contract_id: ContractId,
+        transitions: impl IntoIterator<Item = (OutPoint, &'t Transition, Txid)>,
+    ) -> Result<Vec<Renomination>, RenominationError> {
+        transitions
+            .into_iter()
+            .map(|(closes, transition, witness)| {
+                Renomination::with(contract_id, closes, transition, witness)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn txid(byte: u8) -> Txid {
+        Txid::from_slice(&[byte; 32]).expect("32-byte slice is a valid txid")
+    }
+
+    fn outpoint(byte: u8, vout: u32) -> OutPoint {
+        OutPoint::new(txid(byte), vout)
+    }
+
+    fn epoch(no: usize, closes: OutPoint, epoch_seal: Option<OutPoint>) -> Epoch {
+        Epoch {
+            node_id: NodeId::default(),
+            no,
+            contract_id: ContractId::default(),
+            closes,
+            epoch_seal,
+            seal: None,
+            is_final: epoch_seal.is_none(),
+            is_unlocked: false,
+            known_operations: vec![],
+            witness: txid(0xff),
+        }
+    }
+
+    #[test]
+    fn epoch_chain_walks_to_completion() {
+        let e1 = epoch(1, outpoint(1, 0), Some(outpoint(2, 0)));
+        let e2 = epoch(2, outpoint(2, 0), None);
+        let chain = EpochChain::with(vec![e1, e2]).expect("chain is well-formed");
+        assert!(chain.is_complete());
+    }
+
+    #[test]
+    fn epoch_chain_detects_gap() {
+        let e1 = epoch(1, outpoint(1, 0), Some(outpoint(2, 0)));
+        // the epoch closing outpoint(2, 0) is missing; only a later,
+        // unrelated-looking epoch is known
+        let e3 = epoch(3, outpoint(3, 0), None);
+        let err = EpochChain::with(vec![e1, e3]).unwrap_err();
+        assert_eq!(err, EpochChainError::Gap(outpoint(2, 0)));
+    }
+
+    #[test]
+    fn epoch_chain_flags_unreachable_epoch_after_final() {
+        let e1 = epoch(1, outpoint(1, 0), Some(outpoint(2, 0)));
+        let e2 = epoch(2, outpoint(2, 0), None);
+        // e3 closes an outpoint that no epoch in the chain ever opened; the
+        // chain already terminated at e2, so e3 never gets walked and must
+        // not be reported as a `Gap` in the chain itself
+        let e3 = epoch(3, outpoint(9, 0), None);
+        let err = EpochChain::with(vec![e1, e2, e3]).unwrap_err();
+        assert!(matches!(err, EpochChainError::Unreachable(_, closes) if closes == outpoint(9, 0)));
+    }
+
+    #[test]
+    fn epoch_chain_detects_branch() {
+        let e1 = epoch(1, outpoint(1, 0), Some(outpoint(2, 0)));
+        let e2a = epoch(2, outpoint(2, 0), None);
+        let e2b = epoch(2, outpoint(2, 0), None);
+        let err = EpochChain::with(vec![e1, e2a, e2b]).unwrap_err();
+        assert!(matches!(err, EpochChainError::Branch(_, seal) if seal == outpoint(2, 0)));
+    }
+
+    fn issue(
+        amount: AtomicValue,
+        closes: BTreeSet<OutPoint>,
+        inflation_assignments: BTreeMap<OutPoint, (AtomicValue, Vec<u16>)>,
+    ) -> Issue {
+        let witness = if closes.is_empty() { None } else { Some(txid(0xee)) };
+        Issue {
+            node_id: NodeId::default(),
+            contract_id: ContractId::default(),
+            amount,
+            closes,
+            inflation_assignments,
+            witness,
+        }
+    }
+
+    #[test]
+    fn inflation_ledger_resolves_out_of_order_secondary_issues() {
+        let seal_a = outpoint(1, 0);
+        let seal_b = outpoint(2, 0);
+
+        let mut genesis_inflation = BTreeMap::new();
+        genesis_inflation.insert(seal_a, (1_000, vec![0]));
+        let genesis = issue(500, BTreeSet::new(), genesis_inflation);
+
+        // second-generation issue: spends `seal_b`, which is only
+        // introduced by the first-generation issue below, and is supplied
+        // *before* it in iteration order
+        let mut second_gen_closes = BTreeSet::new();
+        second_gen_closes.insert(seal_b);
+        let second_gen = issue(200, second_gen_closes, BTreeMap::new());
+
+        let mut first_gen_closes = BTreeSet::new();
+        first_gen_closes.insert(seal_a);
+        let mut first_gen_inflation = BTreeMap::new();
+        first_gen_inflation.insert(seal_b, (300, vec![0]));
+        let first_gen = issue(700, first_gen_closes, first_gen_inflation);
+
+        let ledger = InflationLedger::with(&genesis, vec![second_gen, first_gen])
+            .expect("dependency-ordered resolution should succeed regardless of input order");
+        assert_eq!(ledger.remaining_inflation(), 0);
+    }
+
+    #[test]
+    fn inflation_ledger_flags_over_issuance() {
+        let seal_a = outpoint(1, 0);
+        let mut genesis_inflation = BTreeMap::new();
+        genesis_inflation.insert(seal_a, (100, vec![0]));
+        let genesis = issue(500, BTreeSet::new(), genesis_inflation);
+
+        let mut closes = BTreeSet::new();
+        closes.insert(seal_a);
+        let over_issued = issue(200, closes, BTreeMap::new());
+
+        let err = InflationLedger::with(&genesis, vec![over_issued]).unwrap_err();
+        assert!(matches!(err, InflationLedgerError::OverIssuance(_, 200, 100)));
+    }
+
+    #[test]
+    fn inflation_ledger_flags_unresolvable_seal() {
+        let genesis = issue(500, BTreeSet::new(), BTreeMap::new());
+
+        let mut closes = BTreeSet::new();
+        closes.insert(outpoint(9, 0));
+        let orphan = issue(50, closes, BTreeMap::new());
+
+        let err = InflationLedger::with(&genesis, vec![orphan]).unwrap_err();
+        assert!(matches!(err, InflationLedgerError::UnknownSeal(_, seal) if seal == outpoint(9, 0)));
+    }
+
+    #[test]
+    fn issue_consistency_checks_witness_presence() {
+        let mut primary = issue(500, BTreeSet::new(), BTreeMap::new());
+        assert!(primary.is_consistent().is_ok());
+
+        primary.witness = Some(txid(1));
+        assert!(primary.is_consistent().is_err());
+
+        primary.make_consistent();
+        assert!(primary.is_consistent().is_ok());
+    }
+
+    #[test]
+    fn epoch_consistency_rejects_operations_without_an_unlocking_seal() {
+        let mut locked = epoch(1, outpoint(1, 0), None);
+        assert!(locked.is_consistent().is_ok());
+
+        locked.known_operations.push(BurnReplace {
+            node_id: NodeId::default(),
+            epoch_id: locked.node_id,
+            no: 1,
+            contract_id: ContractId::default(),
+            closes: outpoint(2, 0),
+            does_replacement: false,
+            burned_amount: 100,
+            replaced_amount: 0,
+            supply_change: 100,
+            is_final: true,
+            seal: None,
+            witness: txid(2),
+        });
+
+        let err = locked.is_consistent().unwrap_err();
+        assert!(matches!(err, InconsistencyError::EpochOperationsWithoutSeal(_)));
+    }
+
+    fn burn_replace(supply_change: AtomicValue, witness: Txid) -> BurnReplace {
+        BurnReplace {
+            node_id: NodeId::default(),
+            epoch_id: NodeId::default(),
+            no: 1,
+            contract_id: ContractId::default(),
+            closes: outpoint(0xaa, 0),
+            does_replacement: false,
+            burned_amount: supply_change,
+            replaced_amount: 0,
+            supply_change,
+            is_final: true,
+            seal: None,
+            witness,
+        }
+    }
+
+    /// Fake resolver backed by a fixed table of witness statuses, standing
+    /// in for a real blockchain/state-channel lookup in tests.
+    struct FakeResolver(BTreeMap<Txid, WitnessStatus>);
+
+    impl WitnessResolver for FakeResolver {
+        fn witness_info(&self, txid: Txid) -> Option<WitnessStatus> {
+            self.0.get(&txid).copied()
+        }
+    }
+
+    #[test]
+    fn confirmed_circulating_nets_issuance_against_confirmed_only_burns() {
+        let confirmed_witness = txid(1);
+        let mempool_witness = txid(2);
+        let unknown_witness = txid(3);
+
+        let resolver = FakeResolver(
+            vec![
+                (confirmed_witness, WitnessStatus::with(Some(100), 6)),
+                (mempool_witness, WitnessStatus::with(None, 0)),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let primary_issue = issue(1000, BTreeSet::new(), BTreeMap::new());
+        let mut confirmed_secondary =
+            issue(500, BTreeSet::from([outpoint(1, 0)]), BTreeMap::new());
+        confirmed_secondary.witness = Some(confirmed_witness);
+        let mut pending_secondary =
+            issue(250, BTreeSet::from([outpoint(2, 0)]), BTreeMap::new());
+        pending_secondary.witness = Some(mempool_witness);
+        let mut unresolved_secondary =
+            issue(125, BTreeSet::from([outpoint(3, 0)]), BTreeMap::new());
+        unresolved_secondary.witness = Some(unknown_witness);
+        let issues = vec![
+            primary_issue,
+            confirmed_secondary,
+            pending_secondary,
+            unresolved_secondary,
+        ];
+
+        let burn_replaces = vec![
+            burn_replace(300, confirmed_witness),
+            burn_replace(1_000_000, mempool_witness),
+        ];
+
+        let supply = Supply::with(0, None, 0);
+        let confirmed = supply.confirmed_circulating(&issues, &burn_replaces, &resolver, 6);
+
+        // only the primary issue (no witness, always confirmed) and the
+        // confirmed secondary issue count towards issuance; only the
+        // confirmed burn counts against it. The mempool-only issue, burn and
+        // the issue whose witness the resolver does not know are excluded
+        // entirely.
+        assert_eq!(confirmed, 1000 + 500 - 300);
+    }
+
+    #[test]
+    fn renomination_new_rejects_missing_schema_fields() {
+        let err = Renomination::new(
+            NodeId::default(),
+            ContractId::default(),
+            outpoint(1, 0),
+            None,
+            Some(s("USDT")),
+            Some(2),
+            None,
+            txid(1),
+        )
+        .unwrap_err();
+        assert!(matches!(err, RenominationError::UnsatisfiedSchemaRequirement(_)));
+    }
+
+    #[test]
+    fn renomination_new_builds_from_complete_fields() {
+        let renomination = Renomination::new(
+            NodeId::default(),
+            ContractId::default(),
+            outpoint(1, 0),
+            Some(s("USDT")),
+            Some(s("Tether")),
+            Some(2),
+            Some(outpoint(2, 0)),
+            txid(1),
+        )
+        .unwrap();
+        assert_eq!(renomination.ticker, "USDT");
+        assert_eq!(renomination.name, "Tether");
+        assert_eq!(renomination.precision, 2);
+        assert_eq!(renomination.seal, Some(outpoint(2, 0)));
+    }
+
+    fn s(value: &str) -> String {
+        value.to_string()
+    }
+}